@@ -1,6 +1,10 @@
 use crate::utils::*;
 use burn::prelude::*;
 use plotters::prelude::*;
+use plotters::style::colors::colormaps::{ColorMap, ViridisRGB};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
 
 /// The default caption for the chart
 const CAPTION: &str = "fast-umap";
@@ -8,6 +12,146 @@ const CAPTION: &str = "fast-umap";
 /// The default path where the plot will be saved
 const PATH: &str = "plot.png";
 
+/// Default yaw (in radians) applied to 3D embedding plots
+const PROJECTION_YAW: f64 = 0.3;
+
+/// Default pitch (in radians) applied to 3D embedding plots
+const PROJECTION_PITCH: f64 = 0.3;
+
+/// Default scale applied to 3D embedding plots
+const PROJECTION_SCALE: f64 = 1.0;
+
+/// Default delay (in milliseconds) between frames of an animated GIF
+const GIF_FRAME_DELAY_MS: u32 = 200;
+
+/// Default number of bins per axis for `chart_density`
+const DENSITY_GRID_RESOLUTION: u32 = 50;
+
+/// A headless drawing backend that rasterizes into a character grid instead of pixels,
+/// for rendering charts in CI logs and SSH sessions with no image viewer. The cell
+/// buffer is shared via `Rc<RefCell<_>>` so it can be read back after the backend has
+/// been consumed by a `DrawingArea`
+struct TextDrawingBackend {
+    width: u32,
+    height: u32,
+    cells: Rc<RefCell<Vec<char>>>,
+}
+
+impl TextDrawingBackend {
+    fn new(width: u32, height: u32) -> (Self, Rc<RefCell<Vec<char>>>) {
+        let cells = Rc::new(RefCell::new(vec![' '; (width * height) as usize]));
+        (
+            TextDrawingBackend {
+                width,
+                height,
+                cells: cells.clone(),
+            },
+            cells,
+        )
+    }
+}
+
+/// Render a character grid of the given width to a newline-separated string
+fn render_text_grid(cells: &[char], width: u32) -> String {
+    cells
+        .chunks(width as usize)
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl DrawingBackend for TextDrawingBackend {
+    type ErrorType = std::convert::Infallible;
+
+    fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        Ok(())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: (i32, i32),
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
+        let (x, y) = point;
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return Ok(());
+        }
+
+        if color.alpha <= 0.0 {
+            return Ok(());
+        }
+
+        // Every opaque plotters fill (including `root.fill(&WHITE)`) is drawn at
+        // alpha 1.0, so shading can't be keyed on alpha alone. Instead shade by how
+        // far the pixel's RGB value sits from the white background: untouched
+        // background stays blank, axes/text/points (dark or saturated) get shaded.
+        let (r, g, b) = color.rgb;
+        let dist_from_white = {
+            let dr = 255.0 - r as f64;
+            let dg = 255.0 - g as f64;
+            let db = 255.0 - b as f64;
+            (dr * dr + dg * dg + db * db).sqrt() / (255.0 * 3f64.sqrt())
+        };
+
+        let shade = match dist_from_white {
+            d if d > 0.6 => '#',
+            d if d > 0.35 => '+',
+            d if d > 0.15 => '.',
+            d if d > 0.03 => ',',
+            _ => return Ok(()),
+        };
+
+        let idx = (y as u32 * self.width + x as u32) as usize;
+        self.cells.borrow_mut()[idx] = shade;
+        Ok(())
+    }
+}
+
+/// Projection controls for 3D embedding plots
+#[derive(Debug, Clone, Copy)]
+pub struct Projection {
+    pub yaw: f64,
+    pub pitch: f64,
+    pub scale: f64,
+}
+
+impl Default for Projection {
+    fn default() -> Self {
+        Projection {
+            yaw: PROJECTION_YAW,
+            pitch: PROJECTION_PITCH,
+            scale: PROJECTION_SCALE,
+        }
+    }
+}
+
+/// Output backend for a rendered chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Rasterize to a PNG file at `ChartConfig::path`
+    Png,
+    /// Render vector output to an SVG file at `ChartConfig::path`
+    Svg,
+    /// Rasterize to an in-memory RGB buffer, returned to the caller instead of written to disk
+    Buffer,
+    /// Rasterize to an ASCII character grid, returned as UTF-8 bytes (nothing is printed)
+    Text,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat::Png
+    }
+}
+
 /// Configuration structure for the chart, including caption, path, width, and height
 #[derive(Debug, Clone)]
 pub struct ChartConfig {
@@ -15,6 +159,10 @@ pub struct ChartConfig {
     pub path: String,
     pub width: u32,
     pub height: u32,
+    pub projection: Projection,
+    pub output_format: OutputFormat,
+    pub gif_frame_delay_ms: u32,
+    pub density_grid_resolution: u32,
 }
 
 impl ChartConfig {
@@ -25,6 +173,10 @@ impl ChartConfig {
             path: Some(PATH.to_string()),
             width: Some(1000),
             height: Some(1000),
+            projection: None,
+            output_format: None,
+            gif_frame_delay_ms: None,
+            density_grid_resolution: None,
         }
     }
 }
@@ -37,6 +189,10 @@ impl Default for ChartConfig {
             path: PATH.to_string(),
             width: 1000,
             height: 1000,
+            projection: Projection::default(),
+            output_format: OutputFormat::default(),
+            gif_frame_delay_ms: GIF_FRAME_DELAY_MS,
+            density_grid_resolution: DENSITY_GRID_RESOLUTION,
         }
     }
 }
@@ -47,6 +203,10 @@ pub struct ChartConfigBuilder {
     path: Option<String>,
     width: Option<u32>,
     height: Option<u32>,
+    projection: Option<Projection>,
+    output_format: Option<OutputFormat>,
+    gif_frame_delay_ms: Option<u32>,
+    density_grid_resolution: Option<u32>,
 }
 
 impl Default for ChartConfigBuilder {
@@ -56,6 +216,10 @@ impl Default for ChartConfigBuilder {
             path: Some(PATH.into()),
             width: None,
             height: None,
+            projection: None,
+            output_format: None,
+            gif_frame_delay_ms: None,
+            density_grid_resolution: None,
         }
     }
 }
@@ -85,6 +249,30 @@ impl ChartConfigBuilder {
         self
     }
 
+    /// Set the yaw, pitch, and scale used to project 3D embedding plots
+    pub fn with_projection(mut self, yaw: f64, pitch: f64, scale: f64) -> Self {
+        self.projection = Some(Projection { yaw, pitch, scale });
+        self
+    }
+
+    /// Set the output backend (PNG file, SVG file, in-memory buffer, or text grid)
+    pub fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+
+    /// Set the per-frame delay (in milliseconds) used by `animate_embedding`
+    pub fn gif_frame_delay_ms(mut self, delay_ms: u32) -> Self {
+        self.gif_frame_delay_ms = Some(delay_ms);
+        self
+    }
+
+    /// Set the number of bins per axis used by `chart_density`
+    pub fn density_grid_resolution(mut self, resolution: u32) -> Self {
+        self.density_grid_resolution = Some(resolution);
+        self
+    }
+
     /// Build and return the final `ChartConfig`
     pub fn build(self) -> ChartConfig {
         ChartConfig {
@@ -92,20 +280,57 @@ impl ChartConfigBuilder {
             path: self.path.unwrap_or_else(|| PATH.to_string()),
             width: self.width.unwrap_or(1000),
             height: self.height.unwrap_or(1000),
+            projection: self.projection.unwrap_or_default(),
+            output_format: self.output_format.unwrap_or_default(),
+            gif_frame_delay_ms: self.gif_frame_delay_ms.unwrap_or(GIF_FRAME_DELAY_MS),
+            density_grid_resolution: self
+                .density_grid_resolution
+                .unwrap_or(DENSITY_GRID_RESOLUTION),
         }
     }
 }
 
 type Float = f64;
 
+/// Per-point coloring for `chart_vector`/`chart_tensor`: either a discrete class per
+/// point (rendered as one legend entry per label) or a continuous scalar (rendered
+/// through a Viridis colormap)
+#[derive(Debug, Clone)]
+pub enum ChartLabels {
+    Discrete(Vec<i64>),
+    Continuous(Vec<f64>),
+}
+
+/// Map a value already normalized to `[0, 1]` through the Viridis colormap
+fn viridis(t: f64) -> RGBColor {
+    ViridisRGB::get_color_normalized(t.clamp(0.0, 1.0), 0.0, 1.0)
+}
+
 /// Plot the 2D chart using the given tensor data and optional chart configuration
 ///
 /// # Arguments
 /// * `data` - A 2D tensor of data points to plot
 /// * `config` - Optional custom chart configuration
-pub fn chart_tensor<B: Backend>(data: Tensor<B, 2>, config: Option<ChartConfig>) {
+pub fn chart_tensor<B: Backend>(
+    data: Tensor<B, 2>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    chart_tensor_with_labels(data, None, config)
+}
+
+/// Plot the 2D chart using the given tensor data, coloring each point by `labels`
+///
+/// # Arguments
+/// * `data` - A 2D tensor of data points to plot
+/// * `labels` - Optional per-point discrete class or continuous scalar to color by
+/// * `config` - Optional custom chart configuration
+pub fn chart_tensor_with_labels<B: Backend>(
+    data: Tensor<B, 2>,
+    labels: Option<ChartLabels>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
     let data: Vec<Vec<Float>> = convert_tensor_to_vector(data);
-    chart_vector(data, config);
+    chart_vector_with_labels(data, labels, config)
 }
 
 /// Plot the 2D chart using the provided data and configuration
@@ -113,50 +338,120 @@ pub fn chart_tensor<B: Backend>(data: Tensor<B, 2>, config: Option<ChartConfig>)
 /// # Arguments
 /// * `data` - A 2D vector of data points to plot
 /// * `config` - Optional custom chart configuration
-pub fn chart_vector(data: Vec<Vec<Float>>, config: Option<ChartConfig>) {
+pub fn chart_vector(
+    data: Vec<Vec<Float>>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    chart_vector_with_labels(data, None, config)
+}
+
+/// Plot the 2D chart using the provided data and configuration, coloring each point by `labels`
+///
+/// # Arguments
+/// * `data` - A 2D vector of data points to plot
+/// * `labels` - Optional per-point discrete class or continuous scalar to color by
+/// * `config` - Optional custom chart configuration
+pub fn chart_vector_with_labels(
+    data: Vec<Vec<Float>>,
+    labels: Option<ChartLabels>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
     let config = config.unwrap_or(ChartConfig::default());
 
-    // Create a drawing area with a size of 800x600 pixels
-    let root = BitMapBackend::new(&config.path, (config.width, config.height)).into_drawing_area();
-    root.fill(&WHITE).unwrap();
+    let (x_range, y_range) = embedding_ranges(&data);
+
+    match config.output_format {
+        OutputFormat::Png => {
+            let root = BitMapBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_scatter(&root, &data, &labels, &config, x_range, y_range)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Svg => {
+            let root = SVGBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_scatter(&root, &data, &labels, &config, x_range, y_range)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Buffer => {
+            let mut buffer = vec![0u8; (config.width * config.height * 3) as usize];
+            {
+                let root = BitMapBackend::with_buffer(&mut buffer, (config.width, config.height))
+                    .into_drawing_area();
+                draw_scatter(&root, &data, &labels, &config, x_range, y_range)?;
+                root.present()?;
+            }
+            Ok(Some(buffer))
+        }
+        OutputFormat::Text => {
+            let (backend, cells) = TextDrawingBackend::new(config.width, config.height);
+            let root = backend.into_drawing_area();
+            draw_scatter(&root, &data, &labels, &config, x_range, y_range)?;
+            root.present()?;
+
+            let text = render_text_grid(&cells.borrow(), config.width);
+            Ok(Some(text.into_bytes()))
+        }
+    }
+}
 
-    // Define the range for x and y axes (include negative values)
+/// Compute the `(x_range, y_range)` spanning a 2D embedding, where x values sit at
+/// even indices and y values at odd indices of each row
+fn embedding_ranges(data: &[Vec<Float>]) -> ((Float, Float), (Float, Float)) {
     let min_x = data
         .iter()
         .flat_map(|v| v.iter().step_by(2)) // x values are at even indices
         .cloned()
         .min_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
+        .unwrap();
 
     let max_x = data
         .iter()
         .flat_map(|v| v.iter().step_by(2)) // x values are at even indices
         .cloned()
         .max_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
+        .unwrap();
 
     let min_y = data
         .iter()
         .flat_map(|v| v.iter().skip(1).step_by(2)) // y values are at odd indices
         .cloned()
         .min_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
+        .unwrap();
 
     let max_y = data
         .iter()
         .flat_map(|v| v.iter().skip(1).step_by(2)) // y values are at odd indices
         .cloned()
         .max_by(|a, b| a.partial_cmp(b).unwrap())
-        .unwrap() as Float;
+        .unwrap();
+
+    ((min_x, max_x), (min_y, max_y))
+}
+
+/// Draw the scatter plot body (axes, points, legend) onto any plotters drawing backend
+fn draw_scatter<DB: DrawingBackend>(
+    root: &DrawingArea<DB, plotters::coord::Shift>,
+    data: &[Vec<Float>],
+    labels: &Option<ChartLabels>,
+    config: &ChartConfig,
+    (min_x, max_x): (Float, Float),
+    (min_y, max_y): (Float, Float),
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
 
     // Create a chart builder with specific size and axis ranges
-    let mut chart = ChartBuilder::on(&root)
-        .caption(config.caption, ("sans-serif", 30))
+    let mut chart = ChartBuilder::on(root)
+        .caption(config.caption.clone(), ("sans-serif", 30))
         .margin(40)
         .x_label_area_size(30)
         .y_label_area_size(30)
-        .build_cartesian_2d(min_x..max_x, min_y..max_y)
-        .unwrap();
+        .build_cartesian_2d(min_x..max_x, min_y..max_y)?;
 
     // Draw the x and y axis
     chart
@@ -165,14 +460,383 @@ pub fn chart_vector(data: Vec<Vec<Float>>, config: Option<ChartConfig>) {
         .y_desc("Y Axis")
         .x_labels(10)
         .y_labels(10)
-        .draw()
-        .unwrap();
+        .draw()?;
+
+    // Plot each vector in the Vec<Vec<F>> as a series of dots, colored by `labels` when given
+    match labels {
+        None => {
+            chart
+                .draw_series(data.iter().map(|values| {
+                    Circle::new(
+                        (values[0], values[1]),
+                        3,
+                        ShapeStyle {
+                            color: RED.to_rgba(),
+                            filled: false,
+                            stroke_width: 1,
+                        },
+                    )
+                }))?
+                .label("UMAP")
+                .legend(move |(x, y)| {
+                    Circle::new(
+                        (x, y),
+                        5,
+                        ShapeStyle {
+                            color: RED.to_rgba(),
+                            filled: true,
+                            stroke_width: 1,
+                        },
+                    )
+                });
+        }
+        Some(ChartLabels::Continuous(values)) => {
+            let min_v = values.iter().cloned().fold(f64::INFINITY, f64::min);
+            let max_v = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let span = (max_v - min_v).max(f64::EPSILON);
+
+            chart
+                .draw_series(data.iter().zip(values.iter()).map(|(point, value)| {
+                    let color = viridis((value - min_v) / span);
+                    Circle::new(
+                        (point[0], point[1]),
+                        3,
+                        ShapeStyle {
+                            color: color.to_rgba(),
+                            filled: true,
+                            stroke_width: 1,
+                        },
+                    )
+                }))?
+                .label(format!("value [{min_v:.2}, {max_v:.2}]"))
+                .legend(move |(x, y)| {
+                    Circle::new(
+                        (x, y),
+                        5,
+                        ShapeStyle {
+                            color: viridis(0.5).to_rgba(),
+                            filled: true,
+                            stroke_width: 1,
+                        },
+                    )
+                });
+        }
+        Some(ChartLabels::Discrete(class_labels)) => {
+            let mut by_label: BTreeMap<i64, Vec<&Vec<Float>>> = BTreeMap::new();
+            for (point, label) in data.iter().zip(class_labels.iter()) {
+                by_label.entry(*label).or_default().push(point);
+            }
 
-    // Plot each vector in the Vec<Vec<F>> as a series of dots
+            let label_count = by_label.len().max(1);
+            for (i, (label, points)) in by_label.into_iter().enumerate() {
+                let color = viridis(i as f64 / (label_count - 1).max(1) as f64);
+                chart
+                    .draw_series(points.into_iter().map(|point| {
+                        Circle::new(
+                            (point[0], point[1]),
+                            3,
+                            ShapeStyle {
+                                color: color.to_rgba(),
+                                filled: true,
+                                stroke_width: 1,
+                            },
+                        )
+                    }))?
+                    .label(format!("class {label}"))
+                    .legend(move |(x, y)| {
+                        Circle::new(
+                            (x, y),
+                            5,
+                            ShapeStyle {
+                                color: color.to_rgba(),
+                                filled: true,
+                                stroke_width: 1,
+                            },
+                        )
+                    });
+            }
+        }
+    }
+
+    // Draw the legend
+    chart
+        .configure_series_labels()
+        .border_style(&BLACK)
+        .draw()?;
+
+    Ok(())
+}
+
+/// Render an animated GIF of a 2D embedding converging over training epochs
+///
+/// # Arguments
+/// * `frames` - The embedding produced after each epoch, in order
+/// * `config` - Optional custom chart configuration (`path` should end in `.gif`)
+pub fn animate_embedding<B: Backend>(
+    frames: Vec<Tensor<B, 2>>,
+    config: Option<ChartConfig>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = config.unwrap_or(ChartConfig::default());
+    let frames: Vec<Vec<Vec<Float>>> = frames.into_iter().map(convert_tensor_to_vector).collect();
+
+    // Compute axis ranges across every frame so the animation doesn't jitter
+    let (mut x_range, mut y_range) = ((Float::INFINITY, Float::NEG_INFINITY), (Float::INFINITY, Float::NEG_INFINITY));
+    for frame in &frames {
+        let (fx, fy) = embedding_ranges(frame);
+        x_range = (x_range.0.min(fx.0), x_range.1.max(fx.1));
+        y_range = (y_range.0.min(fy.0), y_range.1.max(fy.1));
+    }
+
+    let root = BitMapBackend::gif(
+        &config.path,
+        (config.width, config.height),
+        config.gif_frame_delay_ms,
+    )?
+    .into_drawing_area();
+
+    for frame in &frames {
+        draw_scatter(&root, frame, &None, &config, x_range, y_range)?;
+        root.present()?;
+    }
+
+    Ok(())
+}
+
+/// Render a 2D embedding as a density heatmap instead of individual points, for datasets
+/// large enough that a scatter plot fully overplots
+///
+/// # Arguments
+/// * `data` - A 2D vector of data points to bin, with 2 coordinates per row (x, y)
+/// * `config` - Optional custom chart configuration (`density_grid_resolution` sets the bin count per axis)
+pub fn chart_density(
+    data: Vec<Vec<Float>>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    let config = config.unwrap_or(ChartConfig::default());
+    let (x_range, y_range) = embedding_ranges(&data);
+
+    match config.output_format {
+        OutputFormat::Png => {
+            let root = BitMapBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_density(&root, &data, &config, x_range, y_range)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Svg => {
+            let root = SVGBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_density(&root, &data, &config, x_range, y_range)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Buffer => {
+            let mut buffer = vec![0u8; (config.width * config.height * 3) as usize];
+            {
+                let root = BitMapBackend::with_buffer(&mut buffer, (config.width, config.height))
+                    .into_drawing_area();
+                draw_density(&root, &data, &config, x_range, y_range)?;
+                root.present()?;
+            }
+            Ok(Some(buffer))
+        }
+        OutputFormat::Text => {
+            let (backend, cells) = TextDrawingBackend::new(config.width, config.height);
+            let root = backend.into_drawing_area();
+            draw_density(&root, &data, &config, x_range, y_range)?;
+            root.present()?;
+
+            let text = render_text_grid(&cells.borrow(), config.width);
+            Ok(Some(text.into_bytes()))
+        }
+    }
+}
+
+/// Bin `data`'s (x, y) pairs into a `resolution x resolution` grid of counts, row-major
+/// by y. Points exactly at `min_x + x_span` or `min_y + y_span` (the top/right edge of
+/// the span) are clamped into the last bin rather than falling one bin past it.
+fn bin_density(
+    data: &[Vec<Float>],
+    min_x: Float,
+    x_span: Float,
+    min_y: Float,
+    y_span: Float,
+    resolution: usize,
+) -> Vec<u64> {
+    let mut counts = vec![0u64; resolution * resolution];
+    for point in data {
+        let bx = (((point[0] - min_x) / x_span) * resolution as f64) as usize;
+        let by = (((point[1] - min_y) / y_span) * resolution as f64) as usize;
+        let bx = bx.min(resolution - 1);
+        let by = by.min(resolution - 1);
+        counts[by * resolution + bx] += 1;
+    }
+    counts
+}
+
+/// Draw the density heatmap body (binned counts rendered as a colored matrix) onto any
+/// plotters drawing backend
+fn draw_density<DB: DrawingBackend>(
+    root: &DrawingArea<DB, plotters::coord::Shift>,
+    data: &[Vec<Float>],
+    config: &ChartConfig,
+    (min_x, max_x): (Float, Float),
+    (min_y, max_y): (Float, Float),
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+
+    let resolution = config.density_grid_resolution.max(1) as usize;
+    let x_span = (max_x - min_x).max(f64::EPSILON);
+    let y_span = (max_y - min_y).max(f64::EPSILON);
+
+    let counts = bin_density(data, min_x, x_span, min_y, y_span, resolution);
+
+    let max_log_count = counts
+        .iter()
+        .map(|&c| (c as f64 + 1.0).ln())
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+
+    let mut chart = ChartBuilder::on(root)
+        .caption(config.caption.clone(), ("sans-serif", 30))
+        .margin(40)
+        .x_label_area_size(30)
+        .y_label_area_size(30)
+        .build_cartesian_2d(min_x..max_x, min_y..max_y)?;
+
+    chart
+        .configure_mesh()
+        .x_desc("X Axis")
+        .y_desc("Y Axis")
+        .disable_mesh()
+        .draw()?;
+
+    let cell_w = x_span / resolution as f64;
+    let cell_h = y_span / resolution as f64;
+
+    chart.draw_series((0..resolution).flat_map(|by| {
+        (0..resolution).map(move |bx| (bx, by))
+    }).map(|(bx, by)| {
+        let count = counts[by * resolution + bx];
+        let log_count = (count as f64 + 1.0).ln();
+        let color = viridis(log_count / max_log_count);
+        let x0 = min_x + bx as f64 * cell_w;
+        let y0 = min_y + by as f64 * cell_h;
+        Rectangle::new(
+            [(x0, y0), (x0 + cell_w, y0 + cell_h)],
+            color.filled(),
+        )
+    }))?;
+
+    Ok(())
+}
+
+/// Plot a 3D chart using the given tensor data and optional chart configuration
+///
+/// # Arguments
+/// * `data` - A 2D tensor of data points to plot, with 3 columns per row (x, y, z)
+/// * `config` - Optional custom chart configuration
+pub fn chart_tensor_3d<B: Backend>(
+    data: Tensor<B, 2>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    let data: Vec<Vec<Float>> = convert_tensor_to_vector(data);
+    chart_vector_3d(data, config)
+}
+
+/// Plot the 3D chart using the provided data and configuration
+///
+/// # Arguments
+/// * `data` - A 2D vector of data points to plot, with 3 coordinates per row (x, y, z)
+/// * `config` - Optional custom chart configuration
+pub fn chart_vector_3d(
+    data: Vec<Vec<Float>>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    let config = config.unwrap_or(ChartConfig::default());
+
+    match config.output_format {
+        OutputFormat::Png => {
+            let root = BitMapBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_scatter_3d(&root, &data, &config)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Svg => {
+            let root = SVGBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_scatter_3d(&root, &data, &config)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Buffer => {
+            let mut buffer = vec![0u8; (config.width * config.height * 3) as usize];
+            {
+                let root = BitMapBackend::with_buffer(&mut buffer, (config.width, config.height))
+                    .into_drawing_area();
+                draw_scatter_3d(&root, &data, &config)?;
+                root.present()?;
+            }
+            Ok(Some(buffer))
+        }
+        OutputFormat::Text => {
+            let (backend, cells) = TextDrawingBackend::new(config.width, config.height);
+            let root = backend.into_drawing_area();
+            draw_scatter_3d(&root, &data, &config)?;
+            root.present()?;
+
+            let text = render_text_grid(&cells.borrow(), config.width);
+            Ok(Some(text.into_bytes()))
+        }
+    }
+}
+
+/// Draw the 3D scatter plot body (axes, points, legend) onto any plotters drawing backend
+fn draw_scatter_3d<DB: DrawingBackend>(
+    root: &DrawingArea<DB, plotters::coord::Shift>,
+    data: &[Vec<Float>],
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    root.fill(&WHITE)?;
+
+    // Define the range for x, y, and z axes (include negative values)
+    let min_x = data.iter().map(|v| v[0]).fold(f64::INFINITY, f64::min);
+    let max_x = data.iter().map(|v| v[0]).fold(f64::NEG_INFINITY, f64::max);
+
+    let min_y = data.iter().map(|v| v[1]).fold(f64::INFINITY, f64::min);
+    let max_y = data.iter().map(|v| v[1]).fold(f64::NEG_INFINITY, f64::max);
+
+    let min_z = data.iter().map(|v| v[2]).fold(f64::INFINITY, f64::min);
+    let max_z = data.iter().map(|v| v[2]).fold(f64::NEG_INFINITY, f64::max);
+
+    // Create a 3D chart builder with specific size and axis ranges
+    let mut chart = ChartBuilder::on(root)
+        .caption(config.caption.clone(), ("sans-serif", 30))
+        .margin(40)
+        .build_cartesian_3d(min_x..max_x, min_y..max_y, min_z..max_z)?;
+
+    // Rotate and scale the cloud so callers can inspect it from any angle
+    chart.with_projection(|mut pb| {
+        pb.yaw = config.projection.yaw;
+        pb.pitch = config.projection.pitch;
+        pb.scale = config.projection.scale;
+        pb.into_matrix()
+    });
+
+    chart.configure_axes().draw()?;
+
+    // Plot each row in the Vec<Vec<Float>> as a series of points in 3D space
     chart
         .draw_series(data.iter().map(|values| {
             Circle::new(
-                (values[0], values[1]),
+                (values[0], values[1], values[2]),
                 3,
                 ShapeStyle {
                     color: RED.to_rgba(),
@@ -180,8 +844,7 @@ pub fn chart_vector(data: Vec<Vec<Float>>, config: Option<ChartConfig>) {
                     stroke_width: 1,
                 },
             )
-        }))
-        .unwrap()
+        }))?
         .label("UMAP")
         .legend(move |(x, y)| {
             Circle::new(
@@ -196,40 +859,145 @@ pub fn chart_vector(data: Vec<Vec<Float>>, config: Option<ChartConfig>) {
         });
 
     // Draw the legend
-    chart.configure_mesh().draw().unwrap();
+    chart.configure_series_labels().border_style(&BLACK).draw()?;
+
+    Ok(())
+}
+
+/// The default caption for the loss chart
+const LOSS_CAPTION: &str = "Loss Over Epochs";
+
+/// The default path where the loss chart will be saved
+const LOSS_PATH: &str = "loss.png";
 
-    // Save the chart to a file
-    root.present().unwrap();
+/// Colors cycled through for each named series in `plot_loss`
+const LOSS_PALETTE: [RGBColor; 4] = [BLUE, RED, GREEN, MAGENTA];
+
+/// A single named series for `plot_loss`, e.g. training loss or validation loss
+#[derive(Debug, Clone)]
+pub struct LossSeries {
+    pub name: String,
+    pub values: Vec<f64>,
+}
+
+impl LossSeries {
+    /// Create a new named loss series
+    pub fn new(name: &str, values: Vec<f64>) -> Self {
+        LossSeries {
+            name: name.to_string(),
+            values,
+        }
+    }
 }
 
-/// Plot the loss curve over epochs and save it to a file
+/// Plot one or more named loss curves over epochs and save them to a file
 ///
 /// # Arguments
-/// * `losses` - A vector of loss values over multiple epochs
-/// * `output_path` - Path where the plot will be saved
-pub fn plot_loss(losses: Vec<f64>, output_path: &str) -> Result<(), Box<dyn std::error::Error>> {
-    // Calculate the min and max loss values
-    let min_loss = losses.iter().cloned().fold(f64::INFINITY, f64::min);
-    let max_loss = losses.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
-
-    // Add padding to the min and max values for better visualization
-    let padding = 0.1; // 10% padding, adjust as needed
-    let min_loss_with_padding = min_loss - padding * min_loss.abs();
-    let max_loss_with_padding = max_loss + padding * max_loss.abs();
-
-    // Create a drawing area with a width of 800px and a height of 600px
-    let root = BitMapBackend::new(output_path, (800, 600)).into_drawing_area();
+/// * `series` - The named loss curves to plot on the primary Y axis (e.g. train vs. validation loss)
+/// * `secondary` - An optional series (e.g. a learning-rate schedule) plotted against a right-hand secondary Y axis
+/// * `config` - Optional custom chart configuration (defaults to an 800x600 PNG at `loss.png`)
+pub fn plot_loss(
+    series: Vec<LossSeries>,
+    secondary: Option<LossSeries>,
+    config: Option<ChartConfig>,
+) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    let config = config.unwrap_or_else(|| {
+        ChartConfig::builder()
+            .caption(LOSS_CAPTION)
+            .path(LOSS_PATH)
+            .width(800)
+            .height(600)
+            .build()
+    });
+
+    match config.output_format {
+        OutputFormat::Png => {
+            let root = BitMapBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_loss(&root, &series, &secondary, &config)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Svg => {
+            let root = SVGBackend::new(&config.path, (config.width, config.height))
+                .into_drawing_area();
+            draw_loss(&root, &series, &secondary, &config)?;
+            root.present()?;
+            Ok(None)
+        }
+        OutputFormat::Buffer => {
+            let mut buffer = vec![0u8; (config.width * config.height * 3) as usize];
+            {
+                let root = BitMapBackend::with_buffer(&mut buffer, (config.width, config.height))
+                    .into_drawing_area();
+                draw_loss(&root, &series, &secondary, &config)?;
+                root.present()?;
+            }
+            Ok(Some(buffer))
+        }
+        OutputFormat::Text => {
+            let (backend, cells) = TextDrawingBackend::new(config.width, config.height);
+            let root = backend.into_drawing_area();
+            draw_loss(&root, &series, &secondary, &config)?;
+            root.present()?;
+
+            let text = render_text_grid(&cells.borrow(), config.width);
+            Ok(Some(text.into_bytes()))
+        }
+    }
+}
+
+/// Pad a `[min, max]` range outward by 10% of each bound's magnitude for more readable
+/// axis limits; a bound of 0.0 gets no padding
+fn padded_range(min: f64, max: f64) -> (f64, f64) {
+    let padding = 0.1;
+    (min - padding * min.abs(), max + padding * max.abs())
+}
+
+/// Draw the loss curve(s) (axes, lines, legend, optional secondary axis) onto any
+/// plotters drawing backend
+fn draw_loss<DB: DrawingBackend>(
+    root: &DrawingArea<DB, plotters::coord::Shift>,
+    series: &[LossSeries],
+    secondary: &Option<LossSeries>,
+    config: &ChartConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    DB::ErrorType: 'static,
+{
+    let epochs = series.iter().map(|s| s.values.len()).max().unwrap_or(0);
+
+    let min_loss = series
+        .iter()
+        .flat_map(|s| s.values.iter().cloned())
+        .fold(f64::INFINITY, f64::min);
+    let max_loss = series
+        .iter()
+        .flat_map(|s| s.values.iter().cloned())
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (min_loss, max_loss) = padded_range(min_loss, max_loss);
+
     root.fill(&WHITE)?;
 
     // Create a chart builder with padded Y-axis range
-    let mut chart = ChartBuilder::on(&root)
-        .caption("Loss Over Epochs", ("sans-serif", 30))
+    let mut chart = ChartBuilder::on(root)
+        .caption(config.caption.clone(), ("sans-serif", 30))
         .set_label_area_size(LabelAreaPosition::Left, 80)
         .set_label_area_size(LabelAreaPosition::Bottom, 50)
-        .build_cartesian_2d(
-            0..losses.len() as u32,
-            min_loss_with_padding..max_loss_with_padding,
-        )?;
+        .set_label_area_size(LabelAreaPosition::Right, 80)
+        .build_cartesian_2d(0..epochs as u32, min_loss..max_loss)?
+        .set_secondary_coord(
+            0..epochs as u32,
+            secondary
+                .as_ref()
+                .map(|s| {
+                    let min = s.values.iter().cloned().fold(f64::INFINITY, f64::min);
+                    let max = s.values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                    padded_range(min, max)
+                })
+                .map(|(min, max)| min..max)
+                .unwrap_or(0.0..1.0),
+        );
 
     // Draw the chart axes and grid
     chart
@@ -238,20 +1006,176 @@ pub fn plot_loss(losses: Vec<f64>, output_path: &str) -> Result<(), Box<dyn std:
         .x_desc("Epochs")
         .draw()?;
 
-    // Plot the losses as a line
-    chart
-        .draw_series(LineSeries::new(
-            (0..losses.len()).map(|x| (x as u32, losses[x])),
-            &BLUE,
-        ))?
-        .label("Loss")
-        .legend(move |(x, y)| PathElement::new(vec![(x, y)], &RED));
+    // Plot each named series as a line on the primary axis
+    for (series, color) in series.iter().zip(LOSS_PALETTE.iter().cycle()) {
+        let color = *color;
+        chart
+            .draw_series(LineSeries::new(
+                series
+                    .values
+                    .iter()
+                    .enumerate()
+                    .map(|(x, &y)| (x as u32, y)),
+                &color,
+            ))?
+            .label(series.name.clone())
+            .legend(move |(x, y)| PathElement::new(vec![(x, y)], &color));
+    }
+
+    // Plot the secondary series (e.g. a learning-rate schedule) on the right-hand axis
+    if let Some(secondary) = secondary {
+        chart
+            .draw_secondary_series(LineSeries::new(
+                secondary
+                    .values
+                    .iter()
+                    .enumerate()
+                    .map(|(x, &y)| (x as u32, y)),
+                &BLACK,
+            ))?
+            .label(secondary.name.clone())
+            .legend(move |(x, y)| PathElement::new(vec![(x, y)], &BLACK));
+
+        chart
+            .configure_secondary_axes()
+            .y_desc(secondary.name.clone())
+            .draw()?;
+    }
 
     // Draw the legend
     chart.configure_series_labels().draw()?;
 
-    // Format Y-axis labels to handle small floats
-    chart.configure_mesh().y_labels(10).draw()?;
-
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bin_density_clamps_points_at_the_max_edge_into_the_last_bin() {
+        // A point exactly at min_x + x_span / min_y + y_span sits one step past the
+        // last bin's raw index (`bx == resolution`) before clamping.
+        let data = vec![vec![10.0, 10.0]];
+        let counts = bin_density(&data, 0.0, 10.0, 0.0, 10.0, 5);
+        assert_eq!(counts.len(), 25);
+        assert_eq!(counts[4 * 5 + 4], 1, "max-edge point should land in the last bin, not overflow it");
+        assert_eq!(counts.iter().sum::<u64>(), 1);
+    }
+
+    #[test]
+    fn bin_density_places_min_edge_points_in_the_first_bin() {
+        let data = vec![vec![0.0, 0.0]];
+        let counts = bin_density(&data, 0.0, 10.0, 0.0, 10.0, 5);
+        assert_eq!(counts[0], 1);
+        assert_eq!(counts.iter().sum::<u64>(), 1);
+    }
+
+    #[test]
+    fn bin_density_counts_interior_points_in_the_expected_bin() {
+        // x = 6.0 of a [0, 10) span over 5 bins (width 2 each) falls in bin 3;
+        // y = 1.0 falls in bin 0.
+        let data = vec![vec![6.0, 1.0]];
+        let counts = bin_density(&data, 0.0, 10.0, 0.0, 10.0, 5);
+        assert_eq!(counts[0 * 5 + 3], 1);
+        assert_eq!(counts.iter().sum::<u64>(), 1);
+    }
+
+    #[test]
+    fn render_text_grid_with_nothing_drawn_renders_all_blank() {
+        let cells = vec![' '; 3 * 2];
+        assert_eq!(render_text_grid(&cells, 3), "   \n   ");
+    }
+
+    #[test]
+    fn render_text_grid_wraps_rows_at_the_given_width() {
+        let cells = vec!['#', '.', ' ', ',', '+', '#'];
+        assert_eq!(render_text_grid(&cells, 3), "#. \n,+#");
+    }
+
+    fn opaque(rgb: (u8, u8, u8)) -> BackendColor {
+        BackendColor { alpha: 1.0, rgb }
+    }
+
+    #[test]
+    fn draw_pixel_leaves_white_fills_blank() {
+        // `root.fill(&WHITE)` draws every background pixel at alpha 1.0, so white
+        // must stay unshaded or the whole chart renders as a solid block.
+        let (mut backend, cells) = TextDrawingBackend::new(2, 1);
+        backend.draw_pixel((0, 0), opaque((255, 255, 255))).unwrap();
+        assert_eq!(cells.borrow()[0], ' ');
+    }
+
+    #[test]
+    fn draw_pixel_shades_black_as_the_densest_glyph() {
+        let (mut backend, cells) = TextDrawingBackend::new(2, 1);
+        backend.draw_pixel((0, 0), opaque((0, 0, 0))).unwrap();
+        assert_eq!(cells.borrow()[0], '#');
+    }
+
+    #[test]
+    fn draw_pixel_shades_a_near_white_color_with_the_lightest_glyph() {
+        // (240, 240, 240) sits just barely off white (distance ~0.059), above the
+        // ',' threshold but below '.', exercising the lightest non-blank shading band.
+        let (mut backend, cells) = TextDrawingBackend::new(2, 1);
+        backend.draw_pixel((0, 0), opaque((240, 240, 240))).unwrap();
+        assert_eq!(cells.borrow()[0], ',');
+    }
+
+    #[test]
+    fn draw_pixel_skips_fully_transparent_colors() {
+        let (mut backend, cells) = TextDrawingBackend::new(2, 1);
+        let mut color = opaque((0, 0, 0));
+        color.alpha = 0.0;
+        backend.draw_pixel((0, 0), color).unwrap();
+        assert_eq!(cells.borrow()[0], ' ');
+    }
+
+    #[test]
+    fn draw_pixel_ignores_out_of_bounds_points() {
+        let (mut backend, cells) = TextDrawingBackend::new(2, 2);
+        backend.draw_pixel((-1, 0), opaque((0, 0, 0))).unwrap();
+        backend.draw_pixel((0, -1), opaque((0, 0, 0))).unwrap();
+        backend.draw_pixel((2, 0), opaque((0, 0, 0))).unwrap();
+        backend.draw_pixel((0, 2), opaque((0, 0, 0))).unwrap();
+        assert!(cells.borrow().iter().all(|&c| c == ' '));
+    }
+
+    #[test]
+    fn padded_range_pads_a_nonzero_span_by_ten_percent_of_each_bound() {
+        assert_eq!(padded_range(0.0, 10.0), (0.0, 11.0));
+        assert_eq!(padded_range(-10.0, 10.0), (-11.0, 11.0));
+    }
+
+    #[test]
+    fn padded_range_zero_span_at_a_nonzero_value_still_pads() {
+        // min == max == 5.0: a constant loss value across every epoch
+        assert_eq!(padded_range(5.0, 5.0), (4.5, 5.5));
+    }
+
+    #[test]
+    fn projection_default_matches_the_documented_yaw_pitch_and_scale() {
+        let projection = Projection::default();
+        assert_eq!(projection.yaw, PROJECTION_YAW);
+        assert_eq!(projection.pitch, PROJECTION_PITCH);
+        assert_eq!(projection.scale, PROJECTION_SCALE);
+    }
+
+    #[test]
+    fn with_projection_overrides_the_default_yaw_pitch_and_scale() {
+        let config = ChartConfig::builder()
+            .with_projection(1.0, 2.0, 3.0)
+            .build();
+        assert_eq!(config.projection.yaw, 1.0);
+        assert_eq!(config.projection.pitch, 2.0);
+        assert_eq!(config.projection.scale, 3.0);
+    }
+
+    #[test]
+    fn padded_range_zero_span_at_zero_stays_degenerate() {
+        // min == max == 0.0 has no magnitude to pad against, so the range stays a
+        // single point. Pinned here so a future change to the padding formula can't
+        // silently change how this degenerate axis range is handled without a test failing.
+        assert_eq!(padded_range(0.0, 0.0), (0.0, 0.0));
+    }
+}